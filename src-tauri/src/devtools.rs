@@ -0,0 +1,265 @@
+//! Debug-only instrumentation subsystem for live inspection of the running app.
+//!
+//! This module is compiled in only for debug builds (see the `cfg(debug_assertions)`
+//! gate applied at the call site in `lib.rs`), so it adds no size or attack surface
+//! to release binaries. It installs a `tracing` layer that captures span timings and
+//! events (command invocations, IPC traffic, errors/warnings) into a bounded ring
+//! buffer, and serves that buffer to an external web UI over a loopback WebSocket.
+//!
+//! This only captures `tracing`-native spans and events (see the `ipc_invoke` span
+//! around command dispatch in `lib.rs`'s `run()`). The `log` facade is left to
+//! `tauri_plugin_log`, which is registered right after [`init`] and installs its own
+//! global `log` logger; no `log` bridge is set up here, so `log`-facade output (the
+//! log plugin's own lines, any `log::` calls elsewhere) does not show up here.
+
+use std::collections::VecDeque;
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use tracing::field::{Field, Visit};
+use tracing::{Event, Id, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::util::SubscriberInitExt;
+
+/// Default loopback port the devtools WebSocket endpoint listens on.
+///
+/// Overridable with the `TAURI_DEVTOOLS_PORT` environment variable so a developer
+/// running several instances side by side can avoid port clashes.
+const DEFAULT_PORT: u16 = 9223;
+
+/// Maximum number of captured events retained in the ring buffer.
+///
+/// Older events are dropped once this is exceeded, so long-running debug sessions
+/// don't grow memory unbounded.
+const RING_BUFFER_CAPACITY: usize = 2048;
+
+/// A single captured span/event, ready to be serialized to the attached web UI.
+#[derive(Debug, Clone, serde::Serialize)]
+struct CapturedEvent {
+  /// Milliseconds since the devtools subsystem started.
+  timestamp_ms: u64,
+  /// The `tracing` level the event or span was recorded at (e.g. `INFO`, `WARN`).
+  level: String,
+  /// The `tracing` target, typically the module path the event originated from.
+  target: String,
+  /// The formatted event message and fields, or the span's name for span timings.
+  message: String,
+  /// Wall-clock duration of the span, in milliseconds, for entries that represent
+  /// a completed span rather than a bare event.
+  duration_ms: Option<u64>,
+}
+
+/// Bounded, thread-safe buffer of recently captured events.
+#[derive(Clone, Default)]
+struct RingBuffer {
+  events: Arc<Mutex<VecDeque<CapturedEvent>>>,
+}
+
+impl RingBuffer {
+  fn push(&self, event: CapturedEvent) {
+    let mut events = self.events.lock().expect("devtools ring buffer poisoned");
+    if events.len() >= RING_BUFFER_CAPACITY {
+      events.pop_front();
+    }
+    events.push_back(event);
+  }
+
+  fn snapshot(&self) -> Vec<CapturedEvent> {
+    self
+      .events
+      .lock()
+      .expect("devtools ring buffer poisoned")
+      .iter()
+      .cloned()
+      .collect()
+  }
+}
+
+/// Collects a `tracing` event's fields into a single human-readable message.
+#[derive(Default)]
+struct MessageVisitor {
+  message: String,
+}
+
+impl Visit for MessageVisitor {
+  fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+    if field.name() == "message" {
+      self.message = format!("{value:?}");
+    } else {
+      if !self.message.is_empty() {
+        self.message.push(' ');
+      }
+      self.message.push_str(&format!("{}={:?}", field.name(), value));
+    }
+  }
+}
+
+/// Per-span bookkeeping stashed in the span's extensions while it's open.
+///
+/// Recorded in `on_new_span` and consumed in `on_close` so we can report how long
+/// each command/IPC span actually took, giving the "perf timings" the instrumentation
+/// subsystem is meant to provide.
+struct SpanTiming {
+  started_at: std::time::Instant,
+  name: &'static str,
+  target: String,
+  level: String,
+}
+
+/// A `tracing_subscriber` layer that records events and span durations into a
+/// [`RingBuffer`].
+///
+/// Installed only behind `cfg(debug_assertions)`; see [`init`].
+struct DevtoolsLayer {
+  started_at: std::time::Instant,
+  buffer: RingBuffer,
+}
+
+impl<S> Layer<S> for DevtoolsLayer
+where
+  S: Subscriber + for<'a> LookupSpan<'a>,
+{
+  fn on_new_span(&self, attrs: &tracing::span::Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+    let Some(span) = ctx.span(id) else { return };
+    span.extensions_mut().insert(SpanTiming {
+      started_at: std::time::Instant::now(),
+      name: attrs.metadata().name(),
+      target: attrs.metadata().target().to_string(),
+      level: attrs.metadata().level().to_string(),
+    });
+  }
+
+  fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+    let Some(span) = ctx.span(&id) else { return };
+    let Some(timing) = span.extensions_mut().remove::<SpanTiming>() else {
+      return;
+    };
+
+    self.buffer.push(CapturedEvent {
+      timestamp_ms: self.started_at.elapsed().as_millis() as u64,
+      level: timing.level,
+      target: timing.target,
+      message: timing.name.to_string(),
+      duration_ms: Some(timing.started_at.elapsed().as_millis() as u64),
+    });
+  }
+
+  fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+    let mut visitor = MessageVisitor::default();
+    event.record(&mut visitor);
+
+    self.buffer.push(CapturedEvent {
+      timestamp_ms: self.started_at.elapsed().as_millis() as u64,
+      level: event.metadata().level().to_string(),
+      target: event.metadata().target().to_string(),
+      message: visitor.message,
+      duration_ms: None,
+    });
+  }
+}
+
+/// Serves ring-buffer snapshots to a single attached WebSocket client at a time.
+///
+/// Runs on its own thread so it never blocks the Tauri event loop; exits quietly
+/// if the configured port is already taken, since devtools is a nice-to-have and
+/// must never stop the app from starting. A second concurrent connection attempt
+/// is accepted and closed immediately rather than spawning another polling
+/// thread, since only one attached web UI is supported at a time.
+fn serve(buffer: RingBuffer, port: u16) {
+  let listener = match TcpListener::bind(("127.0.0.1", port)) {
+    Ok(listener) => listener,
+    Err(err) => {
+      tracing::warn!("devtools: failed to bind loopback port {port}: {err}");
+      return;
+    }
+  };
+
+  let client_connected = Arc::new(AtomicBool::new(false));
+
+  for stream in listener.incoming() {
+    let Ok(stream) = stream else { continue };
+
+    if client_connected
+      .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+      .is_err()
+    {
+      // Already serving one client; drop this connection instead of queuing another.
+      continue;
+    }
+
+    let buffer = buffer.clone();
+    let client_connected = client_connected.clone();
+    std::thread::spawn(move || {
+      let _guard = reset_client_flag_on_drop(&client_connected);
+      let Ok(mut socket) = tungstenite::accept(stream) else {
+        return;
+      };
+      loop {
+        let snapshot = buffer.snapshot();
+        let Ok(payload) = serde_json::to_string(&snapshot) else {
+          break;
+        };
+        if socket
+          .send(tungstenite::Message::Text(payload))
+          .is_err()
+        {
+          break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(500));
+      }
+    });
+  }
+}
+
+/// Clears the single-client flag when the serving thread's guard is dropped,
+/// regardless of which `break`/return path ended the connection.
+fn reset_client_flag_on_drop(client_connected: &Arc<AtomicBool>) -> impl Drop + '_ {
+  struct Guard<'a>(&'a AtomicBool);
+  impl Drop for Guard<'_> {
+    fn drop(&mut self) {
+      self.0.store(false, Ordering::SeqCst);
+    }
+  }
+  Guard(client_connected)
+}
+
+/// Installs the devtools `tracing` layer and starts the loopback WebSocket server.
+///
+/// Call this once from inside the `cfg(debug_assertions)` branch of `setup`, before
+/// registering `tauri_plugin_log` — this only wires up a `tracing` subscriber, it
+/// does not touch the `log` facade, so registration order with the log plugin
+/// doesn't matter for this call itself. The port defaults to [`DEFAULT_PORT`] and
+/// can be overridden with the `TAURI_DEVTOOLS_PORT` environment variable.
+///
+/// # Examples
+///
+/// ```ignore
+/// // Illustrative only: `devtools` is a private module, so this does not resolve
+/// // as a doctest. See `run()` in lib.rs for the real call site.
+/// if cfg!(debug_assertions) {
+///     devtools::init();
+/// }
+/// ```
+pub fn init() {
+  let port = std::env::var("TAURI_DEVTOOLS_PORT")
+    .ok()
+    .and_then(|value| value.parse().ok())
+    .unwrap_or(DEFAULT_PORT);
+
+  let buffer = RingBuffer::default();
+  let layer = DevtoolsLayer {
+    started_at: std::time::Instant::now(),
+    buffer: buffer.clone(),
+  };
+
+  if let Err(err) = tracing_subscriber::registry()
+    .with(layer)
+    .try_init()
+  {
+    log::warn!("devtools: tracing subscriber already installed: {err}");
+  }
+
+  std::thread::spawn(move || serve(buffer, port));
+}
@@ -1,6 +1,13 @@
+#[cfg(debug_assertions)]
+mod devtools;
+
 /// Starts the Tauri application configured for this crate.
 ///
-/// In debug builds, registers the logging plugin with log level `Info` on the application handle.
+/// In debug builds, starts the devtools instrumentation subsystem (see [`devtools::init`])
+/// before registering the logging plugin, then registers the logging plugin itself with log
+/// level `Info` on the application handle. Every IPC command dispatch is wrapped in an
+/// `ipc_invoke` tracing span so devtools can report command invocations and their timings;
+/// none of this is present in release builds.
 /// This function will panic with "error while running tauri application" if the application fails to start.
 ///
 /// # Examples
@@ -13,8 +20,19 @@
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
   tauri::Builder::default()
+    .invoke_handler({
+      let handler = tauri::generate_handler![];
+      move |invoke| {
+        #[cfg(debug_assertions)]
+        let _span = tracing::info_span!("ipc_invoke", command = invoke.message.command()).entered();
+        handler(invoke)
+      }
+    })
     .setup(|app| {
       if cfg!(debug_assertions) {
+        #[cfg(debug_assertions)]
+        devtools::init();
+
         app.handle().plugin(
           tauri_plugin_log::Builder::default()
             .level(log::LevelFilter::Info)